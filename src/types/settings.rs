@@ -34,6 +34,119 @@ impl From<SettingsObject> for Settings {
     }
 }
 
+/// Environment variable that overrides the `allowWorldReadableSecrets` file
+/// value. Always takes precedence, so static configs that cannot be chmod'd
+/// still load.
+pub const ALLOW_WORLD_READABLE_SECRETS_ENV: &str = "CLAUDE_CODE_ALLOW_WORLD_READABLE_SECRETS";
+
+/// Errors produced while loading a [`Settings`] value.
+#[derive(Debug)]
+pub enum SettingsError {
+    /// The settings file could not be read.
+    Io(std::io::Error),
+    /// The settings file contained invalid JSON.
+    Parse(serde_json::Error),
+    /// The settings file is group/world-readable and may expose secrets.
+    WorldReadable {
+        /// Path to the offending file.
+        path: PathBuf,
+        /// The file's Unix mode.
+        mode: u32,
+    },
+    /// The resolved settings contain fields the detected CLI cannot understand.
+    Unsupported(Vec<UnsupportedField>),
+}
+
+impl std::fmt::Display for SettingsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SettingsError::Io(err) => write!(f, "failed to read settings file: {err}"),
+            SettingsError::Parse(err) => write!(f, "failed to parse settings file: {err}"),
+            SettingsError::WorldReadable { path, mode } => write!(
+                f,
+                "settings file {} is group/world-readable (mode {:o}) and may expose secrets; \
+                 chmod it to 0600 or set {} / allowWorldReadableSecrets to override",
+                path.display(),
+                mode,
+                ALLOW_WORLD_READABLE_SECRETS_ENV
+            ),
+            SettingsError::Unsupported(fields) => {
+                write!(f, "settings contain fields unsupported by the detected CLI:")?;
+                for field in fields {
+                    write!(
+                        f,
+                        " {} (introduced in protocol {}.{})",
+                        field.field, field.introduced_in.0, field.introduced_in.1
+                    )?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for SettingsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SettingsError::Io(err) => Some(err),
+            SettingsError::Parse(err) => Some(err),
+            SettingsError::WorldReadable { .. } => None,
+            SettingsError::Unsupported(_) => None,
+        }
+    }
+}
+
+impl Settings {
+    /// Resolve this `Settings` into a [`SettingsObject`].
+    ///
+    /// For [`Settings::Path`] the file is read and, on Unix, refused if it is
+    /// group/world-readable (`mode & 0o077 != 0`) unless the
+    /// [`ALLOW_WORLD_READABLE_SECRETS_ENV`] environment variable or the
+    /// `allowWorldReadableSecrets` field opts in — the environment variable
+    /// always wins. The permission check is a no-op on Windows.
+    pub fn load(&self) -> Result<SettingsObject, SettingsError> {
+        match self {
+            Settings::Path(path) => {
+                let contents = std::fs::read_to_string(path).map_err(SettingsError::Io)?;
+                let object: SettingsObject =
+                    serde_json::from_str(&contents).map_err(SettingsError::Parse)?;
+                Self::check_permissions(path, &object)?;
+                Ok(object)
+            }
+            Settings::Json(json) => serde_json::from_str(json).map_err(SettingsError::Parse),
+            Settings::Object(object) => Ok(object.clone()),
+        }
+    }
+
+    /// Whether world-readable secrets are permitted, with the environment
+    /// variable overriding the file-level field.
+    #[cfg(unix)]
+    fn world_readable_allowed(object: &SettingsObject) -> bool {
+        match std::env::var(ALLOW_WORLD_READABLE_SECRETS_ENV) {
+            Ok(value) => matches!(value.trim().to_ascii_lowercase().as_str(), "1" | "true" | "yes" | "on"),
+            Err(_) => object.allow_world_readable_secrets.unwrap_or(false),
+        }
+    }
+
+    #[cfg(unix)]
+    fn check_permissions(path: &std::path::Path, object: &SettingsObject) -> Result<(), SettingsError> {
+        use std::os::unix::fs::MetadataExt;
+        let mode = std::fs::metadata(path).map_err(SettingsError::Io)?.mode();
+        if mode & 0o077 != 0 && !Self::world_readable_allowed(object) {
+            return Err(SettingsError::WorldReadable {
+                path: path.to_path_buf(),
+                mode,
+            });
+        }
+        Ok(())
+    }
+
+    #[cfg(not(unix))]
+    fn check_permissions(_path: &std::path::Path, _object: &SettingsObject) -> Result<(), SettingsError> {
+        Ok(())
+    }
+}
+
 /// Structured settings object
 #[derive(Debug, Clone, Default, Serialize, Deserialize, TypedBuilder)]
 #[builder(doc)]
@@ -43,12 +156,325 @@ pub struct SettingsObject {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
     pub sandbox: Option<SandboxSettings>,
+    /// Allow loading this file even when it is group/world-readable.
+    /// Overridden by the [`ALLOW_WORLD_READABLE_SECRETS_ENV`] environment
+    /// variable, which always takes precedence.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub allow_world_readable_secrets: Option<bool>,
     /// Additional settings as raw JSON values
     #[serde(flatten)]
     #[builder(default)]
     pub extra: HashMap<String, serde_json::Value>,
 }
 
+/// Minimum CLI protocol version that introduced each sandbox settings field.
+///
+/// Keyed by the camelCase dotted path as it appears on the wire. Fields absent
+/// from this table are assumed to have existed since the earliest supported
+/// protocol.
+const SANDBOX_FIELD_VERSIONS: &[(&str, (u32, u32))] = &[
+    ("sandbox.enabled", (0, 1)),
+    ("sandbox.autoAllowBashIfSandboxed", (0, 1)),
+    ("sandbox.excludedCommands", (0, 1)),
+    ("sandbox.allowUnsandboxedCommands", (0, 2)),
+    ("sandbox.network.allowUnixSockets", (0, 2)),
+    ("sandbox.network.allowAllUnixSockets", (0, 2)),
+    ("sandbox.network.allowLocalBinding", (0, 3)),
+    ("sandbox.network.httpProxyPort", (0, 3)),
+    ("sandbox.network.socksProxyPort", (0, 3)),
+    ("sandbox.ignoreViolations", (0, 2)),
+    ("sandbox.enableWeakerNestedSandbox", (0, 4)),
+    ("sandbox.seccomp", (0, 5)),
+    ("sandbox.linuxIsolation", (0, 5)),
+];
+
+/// A sandbox field the running CLI does not understand.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnsupportedField {
+    /// camelCase dotted path of the field, e.g. `sandbox.network.allowLocalBinding`.
+    pub field: String,
+    /// Protocol version `(major, minor)` that first introduced the field.
+    pub introduced_in: (u32, u32),
+}
+
+/// Version negotiation layer for validating a [`SettingsObject`] against a
+/// detected Claude Code CLI.
+///
+/// Rather than probing a feature list, the client declares the CLI version it
+/// is talking to and checks the config against [`SANDBOX_FIELD_VERSIONS`],
+/// surfacing fields the binary is too old to understand before launch.
+#[derive(Debug, Clone)]
+pub struct SettingsCompat {
+    /// Human-readable semver string of the detected CLI, e.g. `"1.4.2"`.
+    pub version: String,
+    /// Wire-protocol version as a `(major, minor)` tuple.
+    pub protocol: (u32, u32),
+}
+
+impl SettingsCompat {
+    /// Create a compat layer for a detected CLI `version` and `protocol` tuple.
+    pub fn new(version: impl Into<String>, protocol: (u32, u32)) -> Self {
+        SettingsCompat {
+            version: version.into(),
+            protocol,
+        }
+    }
+
+    /// Validate `settings`, returning the fields this CLI cannot understand.
+    ///
+    /// Returns `Ok(())` when every field set in `settings` was introduced at or
+    /// before the CLI's protocol version, otherwise `Err` with one entry per
+    /// unsupported field.
+    pub fn validate(&self, settings: &SettingsObject) -> Result<(), Vec<UnsupportedField>> {
+        let set = settings.set_field_paths();
+        let unsupported: Vec<UnsupportedField> = SANDBOX_FIELD_VERSIONS
+            .iter()
+            .filter(|(field, introduced_in)| {
+                set.iter().any(|p| p == field) && *introduced_in > self.protocol
+            })
+            .map(|(field, introduced_in)| UnsupportedField {
+                field: (*field).to_string(),
+                introduced_in: *introduced_in,
+            })
+            .collect();
+        if unsupported.is_empty() {
+            Ok(())
+        } else {
+            Err(unsupported)
+        }
+    }
+}
+
+impl SettingsObject {
+    /// Validate this object against a detected CLI `version`, reporting any
+    /// fields the running binary is too old to understand.
+    pub fn validate_for(&self, version: &SettingsCompat) -> Result<(), Vec<UnsupportedField>> {
+        version.validate(self)
+    }
+
+    /// Collect the camelCase dotted paths of the sandbox fields that are set,
+    /// for cross-referencing against [`SANDBOX_FIELD_VERSIONS`].
+    fn set_field_paths(&self) -> Vec<String> {
+        let mut paths = Vec::new();
+        if let Some(sandbox) = &self.sandbox {
+            let mut push = |set: bool, path: &str| {
+                if set {
+                    paths.push(path.to_string());
+                }
+            };
+            push(sandbox.enabled.is_some(), "sandbox.enabled");
+            push(
+                sandbox.auto_allow_bash_if_sandboxed.is_some(),
+                "sandbox.autoAllowBashIfSandboxed",
+            );
+            push(
+                sandbox.excluded_commands.is_some(),
+                "sandbox.excludedCommands",
+            );
+            push(
+                sandbox.allow_unsandboxed_commands.is_some(),
+                "sandbox.allowUnsandboxedCommands",
+            );
+            push(
+                sandbox.ignore_violations.is_some(),
+                "sandbox.ignoreViolations",
+            );
+            push(
+                sandbox.enable_weaker_nested_sandbox.is_some(),
+                "sandbox.enableWeakerNestedSandbox",
+            );
+            push(sandbox.seccomp.is_some(), "sandbox.seccomp");
+            push(
+                sandbox.linux_isolation.is_some(),
+                "sandbox.linuxIsolation",
+            );
+            if let Some(network) = &sandbox.network {
+                push(
+                    network.allow_unix_sockets.is_some(),
+                    "sandbox.network.allowUnixSockets",
+                );
+                push(
+                    network.allow_all_unix_sockets.is_some(),
+                    "sandbox.network.allowAllUnixSockets",
+                );
+                push(
+                    network.allow_local_binding.is_some(),
+                    "sandbox.network.allowLocalBinding",
+                );
+                push(
+                    network.http_proxy_port.is_some(),
+                    "sandbox.network.httpProxyPort",
+                );
+                push(
+                    network.socks_proxy_port.is_some(),
+                    "sandbox.network.socksProxyPort",
+                );
+            }
+        }
+        paths
+    }
+}
+
+/// Layered settings sources resolved into a single effective configuration.
+///
+/// Sources are deep-merged in precedence order — enterprise managed, then
+/// user, project, local, and finally command-line — so a lower-precedence
+/// scope provides defaults that a higher one overrides field-wise. Absent
+/// layers are skipped, matching Claude Code's documented settings hierarchy.
+#[derive(Debug, Clone, Default, TypedBuilder)]
+#[builder(doc)]
+pub struct SettingsLayers {
+    /// Enterprise-managed settings (lowest precedence).
+    #[builder(default, setter(strip_option))]
+    pub enterprise: Option<Settings>,
+    /// Per-user settings.
+    #[builder(default, setter(strip_option))]
+    pub user: Option<Settings>,
+    /// Per-project settings.
+    #[builder(default, setter(strip_option))]
+    pub project: Option<Settings>,
+    /// Project-local settings.
+    #[builder(default, setter(strip_option))]
+    pub local: Option<Settings>,
+    /// Command-line overrides (highest precedence).
+    #[builder(default, setter(strip_option))]
+    pub command_line: Option<Settings>,
+    /// Optional detected CLI version; when set, the resolved object is
+    /// validated against it before being returned.
+    #[builder(default, setter(strip_option))]
+    pub compat: Option<SettingsCompat>,
+}
+
+impl SettingsLayers {
+    /// Load, validate, and fold all configured layers into a single effective
+    /// [`SettingsObject`] in precedence order.
+    ///
+    /// A layer whose file is simply absent is skipped quietly; any other load
+    /// failure — a refused world-readable secret file, unparseable JSON, an I/O
+    /// error — is propagated rather than silently dropped, so the chunk0-4
+    /// hardening still bites when reached through the resolver. When a
+    /// [`SettingsCompat`] is configured, the folded object is validated against
+    /// it and unsupported fields are returned as an error.
+    pub fn resolve(&self) -> Result<SettingsObject, SettingsError> {
+        let mut resolved = SettingsObject::default();
+        for layer in [
+            &self.enterprise,
+            &self.user,
+            &self.project,
+            &self.local,
+            &self.command_line,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            match layer.load() {
+                Ok(object) => resolved = SettingsObject::merge(resolved, object),
+                Err(SettingsError::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => {}
+                Err(err) => return Err(err),
+            }
+        }
+        if let Some(compat) = &self.compat {
+            resolved
+                .validate_for(compat)
+                .map_err(SettingsError::Unsupported)?;
+        }
+        Ok(resolved)
+    }
+}
+
+impl SettingsObject {
+    /// Deep-merge `overlay` onto `base`, returning the combined object.
+    ///
+    /// `sandbox` is merged field-wise so an overlay can flip one knob without
+    /// clobbering the rest; `extra` JSON objects are merged recursively (object
+    /// keys combined, scalars and arrays replaced).
+    pub fn merge(base: SettingsObject, overlay: SettingsObject) -> SettingsObject {
+        let sandbox = match (base.sandbox, overlay.sandbox) {
+            (Some(b), Some(o)) => Some(SandboxSettings::merge(b, o)),
+            (b, o) => o.or(b),
+        };
+        let mut extra = base.extra;
+        for (key, value) in overlay.extra {
+            match extra.get_mut(&key) {
+                Some(existing) => merge_json(existing, &value),
+                None => {
+                    extra.insert(key, value);
+                }
+            }
+        }
+        SettingsObject {
+            sandbox,
+            allow_world_readable_secrets: overlay
+                .allow_world_readable_secrets
+                .or(base.allow_world_readable_secrets),
+            extra,
+        }
+    }
+}
+
+impl SandboxSettings {
+    /// Field-wise merge of two sandbox settings; every field set in `overlay`
+    /// wins, the rest fall back to `base`. Nested network config is merged
+    /// recursively.
+    fn merge(base: SandboxSettings, overlay: SandboxSettings) -> SandboxSettings {
+        let network = match (base.network, overlay.network) {
+            (Some(b), Some(o)) => Some(SandboxNetworkConfig::merge(b, o)),
+            (b, o) => o.or(b),
+        };
+        SandboxSettings {
+            enabled: overlay.enabled.or(base.enabled),
+            auto_allow_bash_if_sandboxed: overlay
+                .auto_allow_bash_if_sandboxed
+                .or(base.auto_allow_bash_if_sandboxed),
+            excluded_commands: overlay.excluded_commands.or(base.excluded_commands),
+            allow_unsandboxed_commands: overlay
+                .allow_unsandboxed_commands
+                .or(base.allow_unsandboxed_commands),
+            network,
+            ignore_violations: overlay.ignore_violations.or(base.ignore_violations),
+            enable_weaker_nested_sandbox: overlay
+                .enable_weaker_nested_sandbox
+                .or(base.enable_weaker_nested_sandbox),
+            seccomp: overlay.seccomp.or(base.seccomp),
+            linux_isolation: overlay.linux_isolation.or(base.linux_isolation),
+        }
+    }
+}
+
+impl SandboxNetworkConfig {
+    /// Field-wise merge of two network configs; fields set in `overlay` win.
+    fn merge(base: SandboxNetworkConfig, overlay: SandboxNetworkConfig) -> SandboxNetworkConfig {
+        SandboxNetworkConfig {
+            allow_unix_sockets: overlay.allow_unix_sockets.or(base.allow_unix_sockets),
+            allow_all_unix_sockets: overlay
+                .allow_all_unix_sockets
+                .or(base.allow_all_unix_sockets),
+            allow_local_binding: overlay.allow_local_binding.or(base.allow_local_binding),
+            http_proxy_port: overlay.http_proxy_port.or(base.http_proxy_port),
+            socks_proxy_port: overlay.socks_proxy_port.or(base.socks_proxy_port),
+        }
+    }
+}
+
+/// Recursively merge `overlay` into `base`: object keys are combined, while
+/// scalars and arrays replace the existing value.
+fn merge_json(base: &mut serde_json::Value, overlay: &serde_json::Value) {
+    match (base, overlay) {
+        (serde_json::Value::Object(base), serde_json::Value::Object(overlay)) => {
+            for (key, value) in overlay {
+                match base.get_mut(key) {
+                    Some(existing) => merge_json(existing, value),
+                    None => {
+                        base.insert(key.clone(), value.clone());
+                    }
+                }
+            }
+        }
+        (base, overlay) => *base = overlay.clone(),
+    }
+}
+
 /// Network configuration for sandbox.
 #[derive(Debug, Clone, Default, Serialize, Deserialize, TypedBuilder)]
 #[builder(doc)]
@@ -90,6 +516,244 @@ pub struct SandboxIgnoreViolations {
     pub network: Option<Vec<String>>,
 }
 
+/// A single argument comparator within a seccomp syscall rule.
+///
+/// Mirrors the OCI runtime spec `LinuxSeccompArg`: the kernel compares the
+/// syscall argument at `index` against `value` (and `value_two` for range
+/// operators) using the comparison operator `op`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TypedBuilder)]
+#[builder(doc)]
+#[serde(rename_all = "camelCase")]
+pub struct SeccompArg {
+    /// Zero-based index of the syscall argument to test.
+    pub index: u32,
+    /// Value the argument is compared against.
+    pub value: u64,
+    /// Second value, used only by range operators (e.g. `SCMP_CMP_MASKED_EQ`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub value_two: Option<u64>,
+    /// Comparison operator, e.g. `SCMP_CMP_EQ` or `SCMP_CMP_MASKED_EQ`.
+    #[builder(setter(into))]
+    pub op: String,
+}
+
+/// A seccomp rule matching one or more syscalls.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TypedBuilder)]
+#[builder(doc)]
+#[serde(rename_all = "camelCase")]
+pub struct SeccompSyscall {
+    /// Syscall names this rule applies to (e.g. `["socket", "connect"]`).
+    #[builder(setter(into))]
+    pub names: Vec<String>,
+    /// Action taken when a listed syscall is invoked, e.g. `SCMP_ACT_ERRNO`.
+    #[builder(setter(into))]
+    pub action: String,
+    /// Optional argument comparators; the rule matches only if all apply.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub args: Option<Vec<SeccompArg>>,
+}
+
+/// An OCI-runtime seccomp profile handed to the kernel when running bash.
+///
+/// Serializes to the standard OCI seccomp JSON blob (`defaultAction`,
+/// `architectures`, `syscalls`) so it can be passed straight through to a
+/// runtime. Use the ready-made constructors for common policies or the
+/// builder to assemble a bespoke rule table.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TypedBuilder)]
+#[builder(doc)]
+#[serde(rename_all = "camelCase")]
+pub struct SeccompProfile {
+    /// Action applied to any syscall not matched by a rule, e.g.
+    /// `SCMP_ACT_ERRNO` (deny) or `SCMP_ACT_ALLOW` (allow).
+    #[builder(setter(into))]
+    pub default_action: String,
+    /// Target architectures, e.g. `["SCMP_ARCH_X86_64", "SCMP_ARCH_AARCH64"]`.
+    #[builder(setter(into))]
+    pub architectures: Vec<String>,
+    /// Per-syscall rules evaluated before `default_action`.
+    #[builder(setter(into))]
+    pub syscalls: Vec<SeccompSyscall>,
+}
+
+impl SeccompProfile {
+    /// Allow-by-default profile that blocks the syscalls used to open network
+    /// connections (`socket`, `connect`, `bind`) so sandboxed bash cannot
+    /// reach the network even when network configuration is bypassed.
+    pub fn deny_network_syscalls() -> Self {
+        SeccompProfile::builder()
+            .default_action("SCMP_ACT_ALLOW")
+            .architectures(vec![
+                "SCMP_ARCH_X86_64".to_string(),
+                "SCMP_ARCH_AARCH64".to_string(),
+            ])
+            .syscalls(vec![SeccompSyscall::builder()
+                .names(vec![
+                    "socket".to_string(),
+                    "connect".to_string(),
+                    "bind".to_string(),
+                ])
+                .action("SCMP_ACT_ERRNO")
+                .build()])
+            .build()
+    }
+
+    /// Deny-by-default profile suited to a minimal exec sandbox: everything is
+    /// blocked except a baseline of syscalls needed to run a process, and
+    /// `ptrace` is explicitly refused.
+    pub fn minimal_exec() -> Self {
+        SeccompProfile::builder()
+            .default_action("SCMP_ACT_ERRNO")
+            .architectures(vec![
+                "SCMP_ARCH_X86_64".to_string(),
+                "SCMP_ARCH_AARCH64".to_string(),
+            ])
+            .syscalls(vec![
+                SeccompSyscall::builder()
+                    .names(vec![
+                        "read".to_string(),
+                        "write".to_string(),
+                        "close".to_string(),
+                        "exit".to_string(),
+                        "exit_group".to_string(),
+                        "execve".to_string(),
+                        "brk".to_string(),
+                        "mmap".to_string(),
+                        "munmap".to_string(),
+                        "rt_sigreturn".to_string(),
+                    ])
+                    .action("SCMP_ACT_ALLOW")
+                    .build(),
+                SeccompSyscall::builder()
+                    .names(vec!["ptrace".to_string()])
+                    .action("SCMP_ACT_ERRNO")
+                    .build(),
+            ])
+            .build()
+    }
+}
+
+/// Linux capability set dropped from or granted to sandboxed bash.
+///
+/// Mirrors the OCI runtime spec `LinuxCapabilities`: each list names the
+/// capabilities retained in the corresponding set (e.g. `CAP_NET_ADMIN`,
+/// `CAP_SYS_PTRACE`). Capabilities not listed are dropped.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TypedBuilder)]
+#[builder(doc)]
+#[serde(rename_all = "camelCase")]
+pub struct LinuxCapabilities {
+    /// Bounding set: the ceiling of capabilities the process may ever hold.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into, strip_option))]
+    pub bounding: Option<Vec<String>>,
+    /// Effective set: capabilities used for permission checks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into, strip_option))]
+    pub effective: Option<Vec<String>>,
+    /// Permitted set: capabilities the process is allowed to make effective.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into, strip_option))]
+    pub permitted: Option<Vec<String>>,
+    /// Ambient set: capabilities preserved across an unprivileged `execve`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into, strip_option))]
+    pub ambient: Option<Vec<String>>,
+}
+
+/// A uid/gid mapping for a rootless user namespace.
+///
+/// Mirrors the OCI runtime spec `LinuxIDMapping`: `size` ids starting at
+/// `host_id` on the host map to ids starting at `container_id` inside the
+/// namespace.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TypedBuilder)]
+#[builder(doc)]
+#[serde(rename_all = "camelCase")]
+pub struct LinuxIdMapping {
+    /// First id inside the namespace.
+    pub container_id: u32,
+    /// First id on the host the range maps to.
+    pub host_id: u32,
+    /// Number of ids in the range.
+    pub size: u32,
+}
+
+/// Linux process-isolation configuration for sandboxed bash.
+///
+/// Wires the OCI runtime spec's capability and namespace knobs into the
+/// sandbox so dangerous capabilities are dropped and the process runs in its
+/// own namespaces. Use [`LinuxIsolation::hardened`] for a safe baseline or the
+/// builder for a bespoke policy.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TypedBuilder)]
+#[builder(doc)]
+#[serde(rename_all = "camelCase")]
+pub struct LinuxIsolation {
+    /// Capabilities retained by the sandboxed process.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub capabilities: Option<LinuxCapabilities>,
+    /// Namespaces the process is unshared into
+    /// (e.g. `["pid", "net", "mount", "user", "uts", "ipc"]`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into, strip_option))]
+    pub namespaces: Option<Vec<String>>,
+    /// uid mappings for a rootless user namespace.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into, strip_option))]
+    pub uid_mappings: Option<Vec<LinuxIdMapping>>,
+    /// gid mappings for a rootless user namespace.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into, strip_option))]
+    pub gid_mappings: Option<Vec<LinuxIdMapping>>,
+}
+
+impl LinuxIsolation {
+    /// Hardened defaults: drop every capability except a safe baseline and
+    /// unshare the process into its own namespaces. Suitable for untrusted
+    /// bash; loosen individual fields with the builder where needed.
+    ///
+    /// Because the baseline includes a `user` namespace, a root-only id mapping
+    /// (container id 0 → host id 0, size 1) is populated so the config is valid
+    /// as-is; callers running truly rootless should override the mappings with
+    /// their unprivileged host uid/gid.
+    pub fn hardened() -> Self {
+        let baseline = vec![
+            "CAP_CHOWN".to_string(),
+            "CAP_DAC_OVERRIDE".to_string(),
+            "CAP_FOWNER".to_string(),
+            "CAP_SETGID".to_string(),
+            "CAP_SETUID".to_string(),
+        ];
+        LinuxIsolation::builder()
+            .capabilities(
+                LinuxCapabilities::builder()
+                    .bounding(baseline.clone())
+                    .effective(baseline.clone())
+                    .permitted(baseline)
+                    .build(),
+            )
+            .namespaces(vec![
+                "pid".to_string(),
+                "net".to_string(),
+                "mount".to_string(),
+                "user".to_string(),
+                "uts".to_string(),
+                "ipc".to_string(),
+            ])
+            .uid_mappings(vec![LinuxIdMapping::builder()
+                .container_id(0)
+                .host_id(0)
+                .size(1)
+                .build()])
+            .gid_mappings(vec![LinuxIdMapping::builder()
+                .container_id(0)
+                .host_id(0)
+                .size(1)
+                .build()])
+            .build()
+    }
+}
+
 /// Sandbox settings configuration.
 ///
 /// This controls how Claude Code sandboxes bash commands for filesystem
@@ -134,4 +798,108 @@ pub struct SandboxSettings {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default, setter(strip_option))]
     pub enable_weaker_nested_sandbox: Option<bool>,
+    /// OCI-runtime seccomp profile applied to sandboxed bash for
+    /// syscall-level isolation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub seccomp: Option<SeccompProfile>,
+    /// Linux namespace and capability-drop configuration for sandboxed bash.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(strip_option))]
+    pub linux_isolation: Option<LinuxIsolation>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_accepts_field_at_exact_introducing_protocol() {
+        let object = SettingsObject::builder()
+            .sandbox(
+                SandboxSettings::builder()
+                    .network(
+                        SandboxNetworkConfig::builder()
+                            .allow_local_binding(true)
+                            .build(),
+                    )
+                    .build(),
+            )
+            .build();
+
+        // allowLocalBinding was introduced in protocol 0.3; an exact match is
+        // supported, one minor older is not.
+        assert!(object
+            .validate_for(&SettingsCompat::new("1.0.0", (0, 3)))
+            .is_ok());
+
+        let err = object
+            .validate_for(&SettingsCompat::new("0.9.0", (0, 2)))
+            .unwrap_err();
+        assert_eq!(err.len(), 1);
+        assert_eq!(err[0].field, "sandbox.network.allowLocalBinding");
+        assert_eq!(err[0].introduced_in, (0, 3));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn env_var_overrides_file_field_for_world_readable_gate() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let mut path = std::env::temp_dir();
+        path.push("claude_agent_sdk_world_readable_test.json");
+        std::fs::write(&path, b"{}").unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644)).unwrap();
+
+        let settings = Settings::Path(path.clone());
+
+        // File has no opt-in field and is world-readable: refused.
+        std::env::remove_var(ALLOW_WORLD_READABLE_SECRETS_ENV);
+        assert!(matches!(
+            settings.load(),
+            Err(SettingsError::WorldReadable { .. })
+        ));
+
+        // Env override wins even though the file field is unset.
+        std::env::set_var(ALLOW_WORLD_READABLE_SECRETS_ENV, "True");
+        assert!(settings.load().is_ok());
+        std::env::remove_var(ALLOW_WORLD_READABLE_SECRETS_ENV);
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn merge_overlays_sandbox_field_wise_and_deep_merges_extra() {
+        let user = SettingsObject::builder()
+            .sandbox(
+                SandboxSettings::builder()
+                    .excluded_commands(vec!["git".to_string()])
+                    .build(),
+            )
+            .extra(HashMap::from([(
+                "permissions".to_string(),
+                serde_json::json!({ "allow": ["Read"], "keep": true }),
+            )]))
+            .build();
+        let project = SettingsObject::builder()
+            .sandbox(SandboxSettings::builder().enabled(true).build())
+            .extra(HashMap::from([(
+                "permissions".to_string(),
+                serde_json::json!({ "allow": ["Edit"] }),
+            )]))
+            .build();
+
+        let merged = SettingsObject::merge(user, project);
+        let sandbox = merged.sandbox.unwrap();
+
+        // Project flipped `enabled` without clobbering the user's commands.
+        assert_eq!(sandbox.enabled, Some(true));
+        assert_eq!(sandbox.excluded_commands, Some(vec!["git".to_string()]));
+
+        // `extra` objects deep-merge: the array is replaced, the untouched
+        // scalar is preserved.
+        let permissions = &merged.extra["permissions"];
+        assert_eq!(permissions["allow"], serde_json::json!(["Edit"]));
+        assert_eq!(permissions["keep"], serde_json::json!(true));
+    }
 }